@@ -6,7 +6,7 @@
 ///! It produces a better approximations than the usual recursive subdivision approach (or
 ///! in other words, it generates less points for a given tolerance threshold).
 
-use super::{Point, CubicBezierSegment};
+use super::{Point, Vector, CubicBezierSegment, QuadraticBezierSegment};
 use up_to_two::UpToTwo;
 
 use std::f32;
@@ -101,6 +101,105 @@ impl Iterator for CubicFlatteningIter {
     }
 }
 
+/// An iterator over a cubic bezier segment that yields line segments approximating the
+/// curve, along with the unit tangent at each emitted vertex, for a given approximation
+/// threshold. For a vertex where the curve's direction of travel is undefined (a
+/// zero-length segment produced by a degenerate curve), the zero vector is yielded
+/// instead of a unit tangent.
+///
+/// This mirrors the inflection-aware stepping of `CubicFlatteningIter`; see its docs for
+/// the iteration behavior.
+pub struct CubicFlattenWithTangentsIter {
+    remaining_curve: CubicBezierSegment,
+    current_curve: Option<CubicBezierSegment>,
+    next_inflection: Option<f32>,
+    following_inflection: Option<f32>,
+    tolerance: f32,
+}
+
+impl CubicFlattenWithTangentsIter {
+    /// Creates an iterator that yields `(point, tangent)` pairs along a cubic bezier
+    /// segment, useful to build a flattened approximation of the curve with per-vertex
+    /// direction information, given a certain tolerance.
+    pub fn new(bezier: CubicBezierSegment, tolerance: f32) -> Self {
+        let inflections = find_cubic_bezier_inflection_points(&bezier);
+
+        let mut iter = CubicFlattenWithTangentsIter {
+            remaining_curve: bezier,
+            current_curve: None,
+            next_inflection: inflections.get(0).cloned(),
+            following_inflection: inflections.get(1).cloned(),
+            tolerance: tolerance,
+        };
+
+        if let Some(&t1) = inflections.get(0) {
+            let (before, after) = bezier.split(t1);
+            iter.current_curve = Some(before);
+            iter.remaining_curve = after;
+            if let Some(&t2) = inflections.get(1) {
+                let t2 = (t2 - t1) / (1.0 - t1);
+                iter.following_inflection = Some(t2)
+            }
+
+            return iter;
+        }
+
+        iter.current_curve = Some(bezier);
+
+        iter
+    }
+}
+
+impl Iterator for CubicFlattenWithTangentsIter {
+    type Item = (Point, Vector);
+    fn next(&mut self) -> Option<(Point, Vector)> {
+        if self.current_curve.is_none() {
+            if self.next_inflection.is_some() {
+                if let Some(t2) = self.following_inflection {
+                    let (before, after) = self.remaining_curve.split(t2);
+                    self.current_curve = Some(before);
+                    self.remaining_curve = after;
+                } else {
+                    self.current_curve = Some(self.remaining_curve);
+                }
+
+                self.next_inflection = self.following_inflection;
+                self.following_inflection = None;
+            }
+        }
+
+        let sub_curve = match self.current_curve {
+            Some(sub_curve) => sub_curve,
+            None => return None,
+        };
+
+        let t = no_inflection_flattening_step(&sub_curve, self.tolerance);
+
+        let (point, local_t) = if t >= 1.0 {
+            self.current_curve = None;
+            (sub_curve.to, 1.0)
+        } else {
+            let next_curve = sub_curve.after_split(t);
+            self.current_curve = Some(next_curve);
+            (next_curve.from, t)
+        };
+
+        let tangent = if sub_curve.from == sub_curve.to {
+            // A degenerate zero-length sub-curve, e.g. produced when an inflection
+            // point sits exactly at the start of the curve: there's no direction to
+            // sample here, so defer to whatever curve continues after it.
+            match self.current_curve {
+                Some(next_curve) => safe_tangent(&next_curve, 0.0),
+                None => safe_tangent(&self.remaining_curve, 0.0),
+            }
+        } else {
+            safe_tangent(&sub_curve, local_t)
+        };
+
+        Some((point, tangent))
+    }
+}
+
 pub fn flatten_cubic_bezier<F: FnMut(Point)>(
     mut bezier: CubicBezierSegment,
     tolerance: f32,
@@ -253,6 +352,457 @@ pub fn find_cubic_bezier_inflection_points(bezier: &CubicBezierSegment) -> UpToT
     ret
 }
 
+impl CubicBezierSegment {
+    /// Returns an iterator that approximates the curve by a sequence of quadratic
+    /// bezier segments, for a given approximation threshold.
+    pub fn to_quadratics(&self, tolerance: f32) -> CubicToQuadraticIter {
+        CubicToQuadraticIter::new(*self, tolerance)
+    }
+
+    /// Approximates the curve by a sequence of quadratic bezier segments, calling
+    /// `call_back` once per segment, for a given approximation threshold.
+    pub fn for_each_quadratic<F: FnMut(QuadraticBezierSegment)>(
+        &self,
+        tolerance: f32,
+        call_back: &mut F,
+    ) {
+        cubic_to_quadratics(*self, tolerance, call_back);
+    }
+
+    /// Approximates the length of this curve to a given tolerance.
+    pub fn approximate_length(&self, tolerance: f32) -> f32 {
+        cubic_length(self, 0.0, 1.0, tolerance)
+    }
+
+    /// Returns the point and unit tangent at the given distance along the curve, measured
+    /// from the start, to a given tolerance.
+    pub fn sample_at_distance(&self, dist: f32, tolerance: f32) -> (Point, Vector) {
+        let t = t_for_distance(self, dist, tolerance);
+        (self.sample(t), safe_tangent(self, t))
+    }
+
+    /// Subdivides this curve at its x- and y-extrema so that each resulting sub-segment
+    /// is monotone in both axes, calling `call_back` once per sub-segment.
+    pub fn for_each_monotonic_split<F: FnMut(CubicBezierSegment)>(&self, call_back: &mut F) {
+        let mut splits = find_cubic_monotonic_split_points(self);
+        splits.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        splits.dedup_by(|a, b| (*a - *b).abs() < 1.0e-6);
+
+        let mut remaining = *self;
+        let mut prev_t = 0.0;
+        for t in splits {
+            // Remap into the remaining curve's parameter space, as the inflection
+            // splitting logic above already does.
+            let local_t = (t - prev_t) / (1.0 - prev_t);
+            let (before, after) = remaining.split(local_t);
+            call_back(before);
+            remaining = after;
+            prev_t = t;
+        }
+
+        call_back(remaining);
+    }
+
+    /// Subdivides this curve at its x- and y-extrema so that each resulting sub-segment
+    /// is monotone in both axes, and returns the sub-segments as a `Vec`.
+    pub fn monotonic_splits(&self) -> Vec<CubicBezierSegment> {
+        let mut result = Vec::new();
+        self.for_each_monotonic_split(&mut |sub_curve| { result.push(sub_curve); });
+        result
+    }
+
+    /// Finds the point on this curve that is closest to `p`, returning the parameter `t`,
+    /// the closest point, and the squared distance to `p`.
+    pub fn closest_point(&self, p: Point) -> (f32, Point, f32) {
+        const NUM_SAMPLES: u32 = 24;
+
+        let mut best_t = 0.0;
+        let mut best_dist_sq = f32::MAX;
+        for i in 0..=NUM_SAMPLES {
+            let t = (i as f32) / (NUM_SAMPLES as f32);
+            let sample = self.sample(t);
+            let dist_sq = (sample - p).square_length();
+            if dist_sq < best_dist_sq {
+                best_dist_sq = dist_sq;
+                best_t = t;
+            }
+        }
+
+        let mut t = best_t;
+        for _ in 0..8 {
+            let sample = self.sample(t);
+            let d = cubic_derivative(self, t);
+            let d2 = cubic_second_derivative(self, t);
+
+            let g = (sample - p).dot(d);
+            let g_prime = d.dot(d) + (sample - p).dot(d2);
+
+            if g_prime == 0.0 {
+                break;
+            }
+
+            let next_t = t - g / g_prime;
+            if next_t.is_nan() || next_t < 0.0 || next_t > 1.0 {
+                break;
+            }
+
+            t = next_t;
+        }
+
+        let sample = self.sample(t);
+        let dist_sq = (sample - p).square_length();
+        if dist_sq <= best_dist_sq {
+            (t, sample, dist_sq)
+        } else {
+            // Newton's method diverged: fall back to the best sampled point.
+            (best_t, self.sample(best_t), best_dist_sq)
+        }
+    }
+
+    /// Returns an iterator that approximates the curve by a sequence of line segments,
+    /// like `flattening_iter`, but also yields the unit tangent at each emitted vertex.
+    pub fn flattening_with_tangents_iter(&self, tolerance: f32) -> CubicFlattenWithTangentsIter {
+        CubicFlattenWithTangentsIter::new(*self, tolerance)
+    }
+}
+
+// The second derivative of the cubic bezier curve at parameter t:
+// d2(t) = 6(1-t)(ctrl2 - 2*ctrl1 + from) + 6t(to - 2*ctrl2 + ctrl1)
+fn cubic_second_derivative(bezier: &CubicBezierSegment, t: f32) -> Vector {
+    (bezier.ctrl2.to_vector() - bezier.ctrl1.to_vector() * 2.0 + bezier.from.to_vector()) * (6.0 * (1.0 - t))
+        + (bezier.to.to_vector() - bezier.ctrl2.to_vector() * 2.0 + bezier.ctrl1.to_vector()) * (6.0 * t)
+}
+
+// Finds the roots in (0, 1) of the derivative of a single component (x or y) of a cubic
+// bezier curve, given the component of `from`, `ctrl1`, `ctrl2` and `to`.
+//
+// The derivative of a single component is the quadratic:
+// 3[(c1-f) + 2((c2-c1)-(c1-f))t + ((t_-c2)-2(c2-c1)+(c1-f))t^2]
+fn monotonic_split_roots(from: f32, ctrl1: f32, ctrl2: f32, to: f32) -> UpToTwo<f32> {
+    let mut ret = UpToTwo::new();
+
+    let a = -from + 3.0 * ctrl1 - 3.0 * ctrl2 + to;
+    let b = 2.0 * (from - 2.0 * ctrl1 + ctrl2);
+    let c = ctrl1 - from;
+
+    fn in_range(t: f32) -> bool { t > 0.0 && t < 1.0 }
+
+    if a == 0.0 {
+        if b != 0.0 {
+            let t = -c / b;
+            if in_range(t) {
+                ret.push(t);
+            }
+        }
+        return ret;
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return ret;
+    }
+
+    if discriminant == 0.0 {
+        let t = -b / (2.0 * a);
+        if in_range(t) {
+            ret.push(t);
+        }
+
+        return ret;
+    }
+
+    let discriminant_sqrt = discriminant.sqrt();
+    let t1 = (-b + discriminant_sqrt) / (2.0 * a);
+    let t2 = (-b - discriminant_sqrt) / (2.0 * a);
+
+    if in_range(t1) {
+        ret.push(t1);
+    }
+    if in_range(t2) {
+        ret.push(t2);
+    }
+
+    ret
+}
+
+// Finds the parameter values at which the curve has a horizontal or vertical tangent.
+fn find_cubic_monotonic_split_points(bezier: &CubicBezierSegment) -> Vec<f32> {
+    let mut result = Vec::new();
+
+    let x_roots = monotonic_split_roots(bezier.from.x, bezier.ctrl1.x, bezier.ctrl2.x, bezier.to.x);
+    if let Some(&t) = x_roots.get(0) {
+        result.push(t);
+    }
+    if let Some(&t) = x_roots.get(1) {
+        result.push(t);
+    }
+
+    let y_roots = monotonic_split_roots(bezier.from.y, bezier.ctrl1.y, bezier.ctrl2.y, bezier.to.y);
+    if let Some(&t) = y_roots.get(0) {
+        result.push(t);
+    }
+    if let Some(&t) = y_roots.get(1) {
+        result.push(t);
+    }
+
+    result
+}
+
+// The derivative of the cubic bezier curve at parameter t:
+// d(t) = 3(1-t)^2 (ctrl1-from) + 6(1-t)t (ctrl2-ctrl1) + 3t^2 (to-ctrl2)
+fn cubic_derivative(bezier: &CubicBezierSegment, t: f32) -> Vector {
+    let t2 = t * t;
+    let one_t = 1.0 - t;
+    let one_t2 = one_t * one_t;
+
+    (bezier.ctrl1 - bezier.from) * (3.0 * one_t2)
+        + (bezier.ctrl2 - bezier.ctrl1) * (6.0 * one_t * t)
+        + (bezier.to - bezier.ctrl2) * (3.0 * t2)
+}
+
+// Returns the unit tangent of `bezier` at parameter `t`. Falls back to a finite
+// difference of nearby samples when the analytic derivative is too small to give a
+// reliable direction (a cusp, or a degenerate zero-length curve), and to the zero
+// vector when the curve has no meaningful direction anywhere near `t` (e.g. it is a
+// single point).
+fn safe_tangent(bezier: &CubicBezierSegment, t: f32) -> Vector {
+    let d = cubic_derivative(bezier, t);
+    if d.square_length() >= 1.0e-6 {
+        return d.normalize();
+    }
+
+    // Widen the sampling window until it picks up a meaningful displacement: a cusp
+    // with a vanishing first derivative can still have a perfectly well defined
+    // direction of travel that only a wider finite difference will capture.
+    let mut epsilon = 0.001;
+    loop {
+        let t0 = (t - epsilon).max(0.0);
+        let t1 = (t + epsilon).min(1.0);
+        let finite_difference = bezier.sample(t1) - bezier.sample(t0);
+
+        if finite_difference.square_length() >= 1.0e-6 {
+            return finite_difference.normalize();
+        }
+
+        if epsilon >= 0.5 {
+            // The curve has no meaningful direction anywhere near `t` (e.g. it is a
+            // single point).
+            return Vector::new(0.0, 0.0);
+        }
+
+        epsilon *= 4.0;
+    }
+}
+
+// 8-point Gauss-Legendre quadrature coefficients (abscissas, weights) over [-1, 1].
+const GAUSS_LEGENDRE_8: [(f32, f32); 8] = [
+    (-0.18343464, 0.36268377),
+    ( 0.18343464, 0.36268377),
+    (-0.5255324, 0.31370664),
+    ( 0.5255324, 0.31370664),
+    (-0.7966665, 0.22238104),
+    ( 0.7966665, 0.22238104),
+    (-0.96028984, 0.101228535),
+    ( 0.96028984, 0.101228535),
+];
+
+// Estimates the length of the bezier segment over the parameter range [t0, t1] using
+// 8-point Gauss-Legendre quadrature on the speed |d(t)|.
+fn gauss_legendre_length(bezier: &CubicBezierSegment, t0: f32, t1: f32) -> f32 {
+    let half = (t1 - t0) * 0.5;
+    let mid = (t0 + t1) * 0.5;
+
+    let mut sum = 0.0;
+    for &(x, w) in GAUSS_LEGENDRE_8.iter() {
+        let t = mid + half * x;
+        let d = cubic_derivative(bezier, t);
+        sum += w * d.x.hypot(d.y);
+    }
+
+    sum * half
+}
+
+// Recursively subdivides [t0, t1] until the Gauss-Legendre estimate of the whole range
+// agrees with the sum of the two halves to within `tolerance`, and returns the length.
+fn cubic_length(bezier: &CubicBezierSegment, t0: f32, t1: f32, tolerance: f32) -> f32 {
+    let whole = gauss_legendre_length(bezier, t0, t1);
+
+    let mid = (t0 + t1) * 0.5;
+    let first_half = gauss_legendre_length(bezier, t0, mid);
+    let second_half = gauss_legendre_length(bezier, mid, t1);
+    let split = first_half + second_half;
+
+    if (whole - split).abs() < tolerance {
+        return split;
+    }
+
+    cubic_length(bezier, t0, mid, tolerance) + cubic_length(bezier, mid, t1, tolerance)
+}
+
+// Finds the parameter t at which the arc length from the start of the curve reaches
+// `dist`, to a given tolerance, by walking successive subdivisions and refining with
+// Newton's method.
+fn t_for_distance(bezier: &CubicBezierSegment, dist: f32, tolerance: f32) -> f32 {
+    if dist <= 0.0 {
+        return 0.0;
+    }
+
+    let total_len = cubic_length(bezier, 0.0, 1.0, tolerance);
+    if dist >= total_len {
+        return 1.0;
+    }
+
+    // Walk subdivisions of increasing refinement until the target distance falls
+    // inside a small enough sub-range, then seed Newton's method from its midpoint.
+    let mut num_steps = 16;
+    let (mut t0, mut t1) = (0.0, 1.0);
+    let mut accumulated = 0.0;
+    loop {
+        let step = (t1 - t0) / (num_steps as f32);
+        let mut t = t0;
+        let mut len_at_t = accumulated;
+        let mut found = false;
+        for _ in 0..num_steps {
+            let next_t = t + step;
+            let seg_len = gauss_legendre_length(bezier, t, next_t);
+            if len_at_t + seg_len >= dist {
+                t0 = t;
+                t1 = next_t;
+                accumulated = len_at_t;
+                found = true;
+                break;
+            }
+            len_at_t += seg_len;
+            t = next_t;
+        }
+
+        if !found {
+            // Numerical imprecision landed us just past the end of the curve.
+            return 1.0;
+        }
+
+        if t1 - t0 < tolerance {
+            break;
+        }
+
+        num_steps = 4;
+    }
+
+    let mut t = (t0 + t1) * 0.5;
+    for _ in 0..8 {
+        let f = accumulated + gauss_legendre_length(bezier, t0, t) - dist;
+        let f_prime = cubic_derivative(bezier, t).x.hypot(cubic_derivative(bezier, t).y);
+        if f_prime == 0.0 {
+            break;
+        }
+
+        let next_t = t - f / f_prime;
+        if next_t < t0 || next_t > t1 || next_t.is_nan() {
+            break;
+        }
+
+        t = next_t;
+    }
+
+    t
+}
+
+/// An iterator that approximates a cubic bezier segment by a sequence of quadratic
+/// bezier segments, for a given approximation threshold.
+///
+/// The curve is split into `n` sub-segments of equal parameter length, each of which
+/// is approximated by a single quadratic bezier segment.
+pub struct CubicToQuadraticIter {
+    remaining_curve: CubicBezierSegment,
+    num_remaining: u32,
+}
+
+impl CubicToQuadraticIter {
+    /// Creates an iterator that yields quadratic bezier segments approximating a cubic
+    /// bezier segment, for a given approximation threshold.
+    pub fn new(bezier: CubicBezierSegment, tolerance: f32) -> Self {
+        CubicToQuadraticIter {
+            remaining_curve: bezier,
+            num_remaining: cubic_to_quadratic_num_segments(&bezier, tolerance),
+        }
+    }
+}
+
+impl Iterator for CubicToQuadraticIter {
+    type Item = QuadraticBezierSegment;
+    fn next(&mut self) -> Option<QuadraticBezierSegment> {
+        if self.num_remaining == 0 {
+            return None;
+        }
+
+        if self.num_remaining == 1 {
+            self.num_remaining = 0;
+            return Some(cubic_to_quadratic(&self.remaining_curve));
+        }
+
+        let t = 1.0 / (self.num_remaining as f32);
+        let (before, after) = self.remaining_curve.split(t);
+        self.remaining_curve = after;
+        self.num_remaining -= 1;
+
+        Some(cubic_to_quadratic(&before))
+    }
+}
+
+pub fn cubic_to_quadratics<F: FnMut(QuadraticBezierSegment)>(
+    bezier: CubicBezierSegment,
+    tolerance: f32,
+    call_back: &mut F,
+) {
+    let num_segments = cubic_to_quadratic_num_segments(&bezier, tolerance);
+    let mut remaining_curve = bezier;
+    for i in 0..num_segments {
+        if i == num_segments - 1 {
+            call_back(cubic_to_quadratic(&remaining_curve));
+            break;
+        }
+
+        let t = 1.0 / ((num_segments - i) as f32);
+        let (before, after) = remaining_curve.split(t);
+        call_back(cubic_to_quadratic(&before));
+        remaining_curve = after;
+    }
+}
+
+// The number of quadratic segments needed to approximate a cubic bezier segment to
+// a given tolerance, splitting the curve into sub-segments of equal parameter length.
+//
+// The per-segment error is driven by the "cubic residual" vector
+// r = to - 3*ctrl2 + 3*ctrl1 - from: the maximum deviation over a sub-segment spanning
+// a parameter range of length h is approximately |r| * h^3 * sqrt(3) / 36.
+fn cubic_to_quadratic_num_segments(bezier: &CubicBezierSegment, tolerance: f32) -> u32 {
+    let r = bezier.to.to_vector() - bezier.ctrl2.to_vector() * 3.0
+        + bezier.ctrl1.to_vector() * 3.0 - bezier.from.to_vector();
+    let r_len = r.x.hypot(r.y);
+
+    if r_len == 0.0 {
+        // The curve is already (close to) quadratic or linear.
+        return 1;
+    }
+
+    let n = (r_len * 3.0f32.sqrt() / (36.0 * tolerance)).cbrt().ceil();
+
+    if n < 1.0 { 1 } else { n as u32 }
+}
+
+// Approximates a cubic bezier segment, assumed to already be small enough not to need
+// further splitting, by a single quadratic bezier segment sharing its endpoints.
+fn cubic_to_quadratic(bezier: &CubicBezierSegment) -> QuadraticBezierSegment {
+    let ctrl = ((bezier.ctrl1.to_vector() + bezier.ctrl2.to_vector()) * 3.0
+        - bezier.from.to_vector() - bezier.to.to_vector()) * 0.25;
+
+    QuadraticBezierSegment {
+        from: bezier.from,
+        ctrl: ctrl.to_point(),
+        to: bezier.to,
+    }
+}
+
 #[cfg(test)]
 fn print_arrays(a: &[Point], b: &[Point]) {
     println!("left:  {:?}", a);
@@ -341,3 +891,224 @@ fn test_issue_19() {
 
     assert!(iter_points.len() > 1);
 }
+
+#[test]
+fn test_cubic_to_quadratics_iter_builder_match() {
+    let tolerance = 0.01;
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 0.0),
+        ctrl2: Point::new(1.0, 1.0),
+        to: Point::new(0.0, 1.0),
+    };
+    let iter_quads: Vec<QuadraticBezierSegment> = c1.to_quadratics(tolerance).collect();
+    let mut builder_quads = Vec::new();
+    c1.for_each_quadratic(tolerance, &mut |q| { builder_quads.push(q); });
+
+    assert!(!iter_quads.is_empty());
+    assert_eq!(iter_quads.len(), builder_quads.len());
+    for (a, b) in iter_quads.iter().zip(builder_quads.iter()) {
+        assert!((a.from.x - b.from.x).abs() < 0.0000001);
+        assert!((a.ctrl.x - b.ctrl.x).abs() < 0.0000001);
+        assert!((a.to.x - b.to.x).abs() < 0.0000001);
+    }
+}
+
+#[test]
+fn test_cubic_to_quadratics_linear_residual() {
+    // A curve whose cubic residual is zero should be approximated by a single quadratic.
+    let tolerance = 0.01;
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 1.0),
+        ctrl2: Point::new(2.0, 2.0),
+        to: Point::new(3.0, 3.0),
+    };
+    let quads: Vec<QuadraticBezierSegment> = c1.to_quadratics(tolerance).collect();
+
+    assert_eq!(quads.len(), 1);
+}
+
+#[test]
+fn test_approximate_length_straight_line() {
+    // A "cubic" that is actually a straight line should have a length close to the
+    // distance between its endpoints.
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 0.0),
+        ctrl2: Point::new(2.0, 0.0),
+        to: Point::new(3.0, 0.0),
+    };
+
+    let len = c1.approximate_length(0.001);
+
+    assert!((len - 3.0).abs() < 0.01);
+}
+
+#[test]
+fn test_sample_at_distance() {
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 0.0),
+        ctrl2: Point::new(2.0, 0.0),
+        to: Point::new(3.0, 0.0),
+    };
+    let tolerance = 0.001;
+    let len = c1.approximate_length(tolerance);
+
+    let (start, _) = c1.sample_at_distance(0.0, tolerance);
+    assert!((start.x - 0.0).abs() < 0.01 && (start.y - 0.0).abs() < 0.01);
+
+    let (end, _) = c1.sample_at_distance(len, tolerance);
+    assert!((end.x - 3.0).abs() < 0.01 && (end.y - 0.0).abs() < 0.01);
+
+    let (mid, tangent) = c1.sample_at_distance(len * 0.5, tolerance);
+    assert!((mid.x - 1.5).abs() < 0.05);
+    assert!((tangent.x - 1.0).abs() < 0.01);
+    assert!(tangent.y.abs() < 0.01);
+}
+
+#[test]
+fn test_sample_at_distance_zero_derivative_at_start() {
+    // ctrl1 == from, so the analytic derivative at t = 0 is zero: make sure this
+    // doesn't produce a NaN tangent.
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(0.0, 0.0),
+        ctrl2: Point::new(1.0, 0.0),
+        to: Point::new(2.0, 0.0),
+    };
+
+    let (_, tangent) = c1.sample_at_distance(0.0, 0.01);
+
+    assert!(!tangent.x.is_nan() && !tangent.y.is_nan());
+    assert!((tangent.x.hypot(tangent.y) - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn test_monotonic_split_already_monotone() {
+    // A curve that is already monotone in both axes should not be split.
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 1.0),
+        ctrl2: Point::new(2.0, 2.0),
+        to: Point::new(3.0, 3.0),
+    };
+
+    let splits = c1.monotonic_splits();
+
+    assert_eq!(splits.len(), 1);
+    assert_approx_eq(&[splits[0].from], &[c1.from]);
+    assert_approx_eq(&[splits[0].to], &[c1.to]);
+}
+
+#[test]
+fn test_monotonic_split_has_extrema() {
+    // This curve has a horizontal tangent somewhere in the middle, so it should be
+    // split into (at least) two monotone pieces.
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 2.0),
+        ctrl2: Point::new(2.0, -2.0),
+        to: Point::new(3.0, 0.0),
+    };
+
+    let splits = c1.monotonic_splits();
+
+    assert!(splits.len() > 1);
+    assert_approx_eq(&[splits[0].from], &[c1.from]);
+    assert_approx_eq(&[splits[splits.len() - 1].to], &[c1.to]);
+}
+
+#[test]
+fn test_monotonic_split_no_zero_length_segments() {
+    // The x-component of this curve's derivative has a double root at t = 0.5: make
+    // sure that doesn't get pushed twice and produce a spurious zero-length split.
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(0.25, 0.0),
+        ctrl2: Point::new(0.0, 0.0),
+        to: Point::new(0.25, 0.0),
+    };
+
+    let splits = c1.monotonic_splits();
+
+    for sub_curve in &splits {
+        assert!((sub_curve.from - sub_curve.to).square_length() > 1.0e-8);
+    }
+}
+
+#[test]
+fn test_closest_point_on_endpoint() {
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 0.0),
+        ctrl2: Point::new(2.0, 0.0),
+        to: Point::new(3.0, 0.0),
+    };
+
+    let (t, point, dist_sq) = c1.closest_point(Point::new(0.0, 0.0));
+
+    assert!(t < 0.01);
+    assert!((point.x - 0.0).abs() < 0.01 && (point.y - 0.0).abs() < 0.01);
+    assert!(dist_sq < 0.0001);
+}
+
+#[test]
+fn test_closest_point_off_curve() {
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 0.0),
+        ctrl2: Point::new(2.0, 0.0),
+        to: Point::new(3.0, 0.0),
+    };
+
+    // The curve is the segment y=0 from x=0 to x=3, so the closest point to (1.5, 1.0)
+    // should be (1.5, 0.0).
+    let (_, point, dist_sq) = c1.closest_point(Point::new(1.5, 1.0));
+
+    assert!((point.x - 1.5).abs() < 0.01);
+    assert!(point.y.abs() < 0.01);
+    assert!((dist_sq - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn test_flattening_with_tangents_matches_flattening() {
+    let tolerance = 0.01;
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 0.0),
+        ctrl2: Point::new(1.0, 1.0),
+        to: Point::new(0.0, 1.0),
+    };
+
+    let points: Vec<Point> = c1.flattening_iter(tolerance).collect();
+    let points_with_tangents: Vec<(Point, Vector)> =
+        c1.flattening_with_tangents_iter(tolerance).collect();
+
+    assert_eq!(points.len(), points_with_tangents.len());
+    for (p, (p2, tangent)) in points.iter().zip(points_with_tangents.iter()) {
+        assert_approx_eq(&[*p], &[*p2]);
+        assert!((tangent.x.hypot(tangent.y) - 1.0).abs() < 0.001);
+    }
+}
+
+#[test]
+fn test_flattening_with_tangents_straight_line() {
+    let tolerance = 0.01;
+    let c1 = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 0.0),
+        ctrl2: Point::new(2.0, 0.0),
+        to: Point::new(3.0, 0.0),
+    };
+
+    let points_with_tangents: Vec<(Point, Vector)> =
+        c1.flattening_with_tangents_iter(tolerance).collect();
+
+    assert!(!points_with_tangents.is_empty());
+    for (_, tangent) in points_with_tangents {
+        assert!((tangent.x - 1.0).abs() < 0.001);
+        assert!(tangent.y.abs() < 0.001);
+    }
+}